@@ -0,0 +1,204 @@
+use crate::veb::VebTree;
+use crate::TableMap;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::{Bound, Range};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+pub struct ColumnIndex<V: Ord + Clone> {
+    rows: BTreeMap<V, Vec<usize>>,
+}
+
+impl<V: Ord + Clone> ColumnIndex<V> {
+    pub fn lookup(&self, value: &V) -> &[usize] {
+        self.rows.get(value).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn range(&self, range: Range<V>) -> impl Iterator<Item = usize> + '_ {
+        self.rows.range(range).flat_map(|(_, rows)| rows.iter().copied())
+    }
+
+    pub fn successor(&self, value: &V) -> Option<&V> {
+        self.rows
+            .range((Bound::Excluded(value.clone()), Bound::Unbounded))
+            .next()
+            .map(|(k, _)| k)
+    }
+
+    pub fn predecessor(&self, value: &V) -> Option<&V> {
+        self.rows
+            .range((Bound::Unbounded, Bound::Excluded(value.clone())))
+            .next_back()
+            .map(|(k, _)| k)
+    }
+
+    pub fn insert(&mut self, value: V, row: usize) {
+        self.rows.entry(value).or_default().push(row);
+    }
+
+    pub fn remove_row(&mut self, value: &V, row: usize) {
+        if let Some(rows) = self.rows.get_mut(value) {
+            rows.retain(|&r| r != row);
+            if rows.is_empty() {
+                self.rows.remove(value);
+            }
+        }
+        for rows in self.rows.values_mut() {
+            for r in rows.iter_mut() {
+                if *r > row {
+                    *r -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn remove_row_swap(&mut self, value: &V, row: usize, last_row: usize) {
+        if let Some(rows) = self.rows.get_mut(value) {
+            rows.retain(|&r| r != row);
+            if rows.is_empty() {
+                self.rows.remove(value);
+            }
+        }
+        if last_row != row {
+            for rows in self.rows.values_mut() {
+                for r in rows.iter_mut() {
+                    if *r == last_row {
+                        *r = row;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<K: PartialEq, V: Ord + Clone> TableMap<K, V> {
+    pub fn build_index<Lookup: PartialEq<K>>(&self, key: &Lookup) -> Option<ColumnIndex<V>> {
+        let column = self.columns.iter().find(|(k, _)| key.eq(k)).map(|(_, v)| v)?;
+        let mut rows: BTreeMap<V, Vec<usize>> = BTreeMap::new();
+        for (row, value) in column.iter().enumerate() {
+            if let Some(value) = value {
+                rows.entry(value.clone()).or_default().push(row);
+            }
+        }
+        Some(ColumnIndex { rows })
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct IntColumnIndex {
+    tree: VebTree,
+    rows: HashMap<u32, Vec<usize>>,
+}
+
+#[cfg(feature = "std")]
+impl IntColumnIndex {
+    pub fn lookup(&self, value: u32) -> &[usize] {
+        self.rows.get(&value).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn successor(&self, value: u32) -> Option<u32> {
+        self.tree.successor(value)
+    }
+
+    pub fn predecessor(&self, value: u32) -> Option<u32> {
+        self.tree.predecessor(value)
+    }
+
+    pub fn range(&self, range: Range<u32>) -> Vec<usize> {
+        let mut result = Vec::new();
+        if range.start >= range.end {
+            return result;
+        }
+        let mut current = if self.rows.contains_key(&range.start) {
+            Some(range.start)
+        } else {
+            self.tree.successor(range.start)
+        };
+        while let Some(value) = current {
+            if value >= range.end {
+                break;
+            }
+            if let Some(rows) = self.rows.get(&value) {
+                result.extend(rows.iter().copied());
+            }
+            current = self.tree.successor(value);
+        }
+        result
+    }
+
+    pub fn insert(&mut self, value: u32, row: usize) {
+        self.tree.insert(value);
+        self.rows.entry(value).or_default().push(row);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: PartialEq> TableMap<K, u32> {
+    pub fn build_veb_index<Lookup: PartialEq<K>>(&self, key: &Lookup) -> Option<IntColumnIndex> {
+        let column = self.columns.iter().find(|(k, _)| key.eq(k)).map(|(_, v)| v)?;
+        let max_value = column.iter().flatten().copied().max().unwrap_or(0);
+        let bits = (32 - max_value.leading_zeros()).max(1);
+        let mut index = IntColumnIndex {
+            tree: VebTree::new(bits),
+            rows: HashMap::new(),
+        };
+        for (row, value) in column.iter().enumerate() {
+            if let Some(value) = value {
+                index.insert(*value, row);
+            }
+        }
+        Some(index)
+    }
+}
+
+#[test]
+#[allow(unused_parens)]
+fn column_index_supports_range_and_neighbours() {
+    let mut table = TableMap::<String, u32>::new();
+    for age in [30u32, 18, 45, 18, 60] {
+        crate::add_entry!(table, {("age".to_owned()): age});
+    }
+    let index = table.build_index(&"age").unwrap();
+    assert_eq!(index.lookup(&18), &[1, 3]);
+    assert_eq!(index.successor(&18), Some(&30));
+    assert_eq!(index.predecessor(&45), Some(&30));
+    let mut in_range: Vec<_> = index.range(18..46).collect();
+    in_range.sort_unstable();
+    assert_eq!(in_range, vec![0, 1, 2, 3]);
+}
+
+#[test]
+#[allow(unused_parens)]
+fn remove_row_swap_follows_swap_remove_entry_semantics() {
+    let mut table = TableMap::<String, String>::new();
+    for name in ["A", "B", "C"] {
+        crate::add_entry!(table, {("name".to_owned()): name.to_owned()});
+    }
+    let mut index = table.build_index(&"name").unwrap();
+
+    let last_row = table.len() - 1;
+    table.swap_remove_entry(0);
+    index.remove_row_swap(&"A".to_owned(), 0, last_row);
+
+    assert_eq!(index.lookup(&"B".to_owned()), &[1]);
+    assert_eq!(index.lookup(&"C".to_owned()), &[0]);
+    assert_eq!(table.entry(0).get(&"name").unwrap(), "C");
+    assert_eq!(table.entry(1).get(&"name").unwrap(), "B");
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[allow(unused_parens)]
+fn veb_index_matches_btree_index() {
+    let mut table = TableMap::<String, u32>::new();
+    for age in [30u32, 18, 45, 18, 60] {
+        crate::add_entry!(table, {("age".to_owned()): age});
+    }
+    let veb = table.build_veb_index(&"age").unwrap();
+    assert_eq!(veb.successor(18), Some(30));
+    assert_eq!(veb.predecessor(45), Some(30));
+    let mut in_range = veb.range(18..46);
+    in_range.sort_unstable();
+    assert_eq!(in_range, vec![0, 1, 2, 3]);
+}
@@ -0,0 +1,124 @@
+use crate::TableMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinKind {
+    Inner,
+    LeftOuter,
+    RightOuter,
+}
+
+impl<V: Eq + Hash + Clone> TableMap<String, V> {
+    pub fn join(&self, self_key: &str, other: &Self, other_key: &str, kind: JoinKind) -> Self {
+        let mut other_rows: HashMap<&V, Vec<usize>> = HashMap::new();
+        for row in 0..other.len() {
+            if let Some(value) = other.entry(row).get(&other_key) {
+                other_rows.entry(value).or_default().push(row);
+            }
+        }
+
+        let self_column_names: HashSet<&String> = self.columns.keys().collect();
+        let mut pairs: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+        let mut matched_other_rows = HashSet::new();
+
+        for self_row in 0..self.len() {
+            let matches = self
+                .entry(self_row)
+                .get(&self_key)
+                .and_then(|value| other_rows.get(value));
+            match matches {
+                Some(rows) if !rows.is_empty() => {
+                    for &other_row in rows {
+                        matched_other_rows.insert(other_row);
+                        pairs.push((Some(self_row), Some(other_row)));
+                    }
+                }
+                _ => {
+                    if kind == JoinKind::LeftOuter {
+                        pairs.push((Some(self_row), None));
+                    }
+                }
+            }
+        }
+
+        if kind == JoinKind::RightOuter {
+            for other_row in 0..other.len() {
+                if !matched_other_rows.contains(&other_row) {
+                    pairs.push((None, Some(other_row)));
+                }
+            }
+        }
+
+        let mut result = TableMap::new();
+        for (self_row, other_row) in pairs {
+            result.new_entry();
+            let dst_row = result.len() - 1;
+            if let Some(self_row) = self_row {
+                for (key, value) in self.entry(self_row).iter() {
+                    result.entry_mut(dst_row).insert(key.clone(), value.clone());
+                }
+            }
+            if let Some(other_row) = other_row {
+                if self_row.is_none() {
+                    if let Some(join_value) = other.entry(other_row).get(&other_key) {
+                        result
+                            .entry_mut(dst_row)
+                            .insert(self_key.to_owned(), join_value.clone());
+                    }
+                }
+                for (key, value) in other.entry(other_row).iter() {
+                    if key == other_key {
+                        continue;
+                    }
+                    let name = if self_column_names.contains(key) {
+                        format!("other_{}", key)
+                    } else {
+                        key.clone()
+                    };
+                    result.entry_mut(dst_row).insert(name, value.clone());
+                }
+            }
+        }
+        result
+    }
+}
+
+#[test]
+#[allow(unused_parens)]
+fn inner_join_matches_on_shared_key() {
+    let mut users = TableMap::<String, String>::new();
+    crate::add_entry!(users, {("id".to_owned()): "1".to_owned(), ("name".to_owned()): "Jon".to_owned()});
+    crate::add_entry!(users, {("id".to_owned()): "2".to_owned(), ("name".to_owned()): "Arya".to_owned()});
+
+    let mut orders = TableMap::<String, String>::new();
+    crate::add_entry!(orders, {("user_id".to_owned()): "1".to_owned(), ("item".to_owned()): "sword".to_owned()});
+
+    let joined = users.join("id", &orders, "user_id", crate::JoinKind::Inner);
+    assert_eq!(joined.len(), 1);
+    assert_eq!(joined.entry(0).get(&"name").unwrap(), "Jon");
+    assert_eq!(joined.entry(0).get(&"item").unwrap(), "sword");
+
+    let left = users.join("id", &orders, "user_id", crate::JoinKind::LeftOuter);
+    assert_eq!(left.len(), 2);
+    assert!(left
+        .entries()
+        .any(|entry| entry.get(&"name") == Some(&"Arya".to_owned()) && entry.get(&"item").is_none()));
+}
+
+#[test]
+#[allow(unused_parens)]
+fn right_outer_join_keeps_join_key_on_unmatched_row() {
+    let mut users = TableMap::<String, String>::new();
+    crate::add_entry!(users, {("id".to_owned()): "1".to_owned(), ("name".to_owned()): "Jon".to_owned()});
+
+    let mut orders = TableMap::<String, String>::new();
+    crate::add_entry!(orders, {("user_id".to_owned()): "1".to_owned(), ("item".to_owned()): "sword".to_owned()});
+    crate::add_entry!(orders, {("user_id".to_owned()): "3".to_owned(), ("item".to_owned()): "shield".to_owned()});
+
+    let right = users.join("id", &orders, "user_id", crate::JoinKind::RightOuter);
+    assert_eq!(right.len(), 2);
+    assert!(right.entries().any(|entry| entry.get(&"id") == Some(&"3".to_owned())
+        && entry.get(&"item") == Some(&"shield".to_owned())
+        && entry.get(&"name").is_none()));
+}
@@ -1,8 +1,37 @@
-use std::fmt::{Debug, Display};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
 use std::fs::{File, OpenOptions};
+#[cfg(feature = "std")]
 use std::path::Path;
 use vector_map::VecMap as Map;
 
+mod csv;
+pub use csv::CsvConfig;
+#[cfg(feature = "std")]
+mod mmap;
+mod veb;
+mod index;
+pub use index::ColumnIndex;
+#[cfg(feature = "std")]
+pub use index::IntColumnIndex;
+#[cfg(feature = "std")]
+mod packed;
+#[cfg(feature = "std")]
+mod join;
+#[cfg(feature = "std")]
+pub use join::JoinKind;
+mod stats;
+pub use stats::ColumnStats;
+
 pub trait CSVFormatable {
     fn format(&self) -> String;
 }
@@ -41,7 +70,7 @@ where
             }
         }))
     }
-    pub fn get<Lookup: PartialEq<K>>(&self, key: &Lookup) -> Option<&V> {
+    pub fn get<Lookup: PartialEq<K>>(&self, key: &Lookup) -> Option<&'l V> {
         if let Some(col) = self.map.columns.get(key) {
             col[self.index].as_ref()
         } else {
@@ -51,7 +80,7 @@ where
 }
 
 impl<'l, K: PartialEq + Debug, V: Debug> Debug for TableEntry<'l, K, V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{{")?;
         for (key, value) in self.iter() {
             write!(f, "{:?}: {:?}, ", key, value)?;
@@ -119,13 +148,13 @@ where
 
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         let mut result = Some(value);
-        std::mem::swap(&mut self.map.column_mut(key)[self.index], &mut result);
+        core::mem::swap(&mut self.map.column_mut(key)[self.index], &mut result);
         result
     }
 }
 
 impl<'l, K: PartialEq + Debug, V: Debug> Debug for TableEntryMut<'l, K, V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{{")?;
         for (key, value) in self.iter() {
             write!(f, "{:?}: {:?}, ", key, value)?;
@@ -271,6 +300,7 @@ where
         self
     }
 
+    #[cfg(feature = "std")]
     pub fn save_ssv<P>(&self, path: P) -> std::io::Result<()>
     where
         P: AsRef<Path>,
@@ -288,6 +318,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl TableMap<String, String> {
     pub fn load_ssv<P>(path: P) -> std::io::Result<Self>
     where
@@ -323,7 +354,9 @@ impl TableMap<String, String> {
         }
         Ok(this)
     }
+}
 
+impl TableMap<String, String> {
     pub fn extract_json(&self) -> serde_json::Result<TableMap<String, serde_json::Value>> {
         use serde_json::from_str;
         let mut result = TableMap::new();
@@ -338,7 +371,7 @@ impl TableMap<String, String> {
 }
 
 impl<K: Display + PartialEq, V: Display> Display for TableMap<K, V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut keys = self.columns.keys();
         if let Some(first_key) = keys.next() {
             write!(f, "{}", first_key)?;
@@ -372,7 +405,7 @@ pub struct SSVTable {
 }
 
 impl Display for SSVTable {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.table.fmt(f)
     }
 }
@@ -452,6 +485,7 @@ impl SSVTable {
         self
     }
 
+    #[cfg(feature = "std")]
     pub fn save_ssv<P>(&self, path: P) -> std::io::Result<()>
     where
         P: AsRef<Path>,
@@ -459,6 +493,7 @@ impl SSVTable {
         self.table.save_ssv(path)
     }
 
+    #[cfg(feature = "std")]
     pub fn load_ssv<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
         TableMap::load_ssv(path).map(|table| SSVTable { table })
     }
@@ -478,6 +513,7 @@ impl<'l> CSVEntryMut<'l> {
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn load() {
     let table = TableMap::load_ssv("test_load.ssv")
@@ -487,6 +523,7 @@ fn load() {
     print!("{}", table);
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn clean() {
     let mut table = TableMap::load_ssv("test_load.ssv").unwrap();
@@ -499,6 +536,7 @@ fn clean() {
     println!("cleaned + read:\n{}", &table);
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn save() {
     let mut table = SSVTable::new();
@@ -527,6 +565,7 @@ macro_rules! add_entry {
     }};
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn bench() {
     let path = "big.ssv";
@@ -540,6 +579,17 @@ fn bench() {
         elapsed,
         size as f32 / elapsed / 1e6
     );
+    let start = std::time::Instant::now();
+    let mmapped = TableMap::<String, String>::load_mmap(path).unwrap();
+    let elapsed = start.elapsed().as_secs_f32();
+    println!(
+        "Parsed {:.2} MB via mmap in {:.3}s: {:.1}MB/s",
+        size as f32 / 1e6,
+        elapsed,
+        size as f32 / elapsed / 1e6
+    );
+    assert_eq!(mmapped.len(), map.len());
+
     let start = std::time::Instant::now();
     let data = format!("{}", map);
     let elapsed = start.elapsed().as_secs_f32();
@@ -561,6 +611,7 @@ fn bench() {
     std::fs::remove_file("big_write.ssv").unwrap();
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn predicates() {
     let table = TableMap::load_ssv("test_load.ssv").unwrap();
@@ -0,0 +1,76 @@
+use crate::TableMap;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::Result;
+use std::path::Path;
+
+fn trim_cr(line: &[u8]) -> &[u8] {
+    if line.last() == Some(&b'\r') {
+        &line[..line.len() - 1]
+    } else {
+        line
+    }
+}
+
+impl TableMap<String, String> {
+    /// Memory-maps `path` and parses it as semicolon-separated values in a
+    /// single pass over the mapped bytes, unlike `load_ssv` which opens the
+    /// file twice (once to count lines, once to read it) and copies every
+    /// cell through an intermediate `BufRead` line.
+    pub fn load_mmap<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(Self::from_ssv_bytes(&mmap))
+    }
+
+    fn from_ssv_bytes(data: &[u8]) -> Self {
+        let mut this = Self::new();
+        let mut lines = data.split(|&b| b == b'\n');
+        let keyline = match lines.next() {
+            Some(line) => trim_cr(line),
+            None => return this,
+        };
+        for key in keyline.split(|&b| b == b';') {
+            let key = String::from_utf8_lossy(key).into_owned();
+            unsafe {
+                this.columns.inner_mut().push((key, Vec::new()));
+            }
+        }
+        for line in lines {
+            let line = trim_cr(line);
+            if line.is_empty() {
+                continue;
+            }
+            for (i, value) in line.split(|&b| b == b';').enumerate() {
+                let value = if value.is_empty() {
+                    None
+                } else {
+                    Some(String::from_utf8_lossy(value).into_owned())
+                };
+                unsafe { &mut this.columns.inner_mut()[i].1 }.push(value);
+            }
+            this.len += 1;
+        }
+        this
+    }
+}
+
+#[test]
+fn load_mmap_matches_load_ssv() {
+    let path = "test_mmap.ssv";
+    let mut table = crate::SSVTable::new();
+    crate::add_entry!(table, {"firstname": "John", "lastname": "Snow"});
+    crate::add_entry!(table, {"firstname": "Arya"});
+    table.save_ssv(path).unwrap();
+
+    let mapped = TableMap::<String, String>::load_mmap(path).unwrap();
+    let read = TableMap::<String, String>::load_ssv(path).unwrap();
+    assert_eq!(mapped.len(), read.len());
+    for i in 0..mapped.len() {
+        assert_eq!(
+            mapped.entry(i).get(&"firstname"),
+            read.entry(i).get(&"firstname")
+        );
+    }
+    std::fs::remove_file(path).unwrap();
+}
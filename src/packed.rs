@@ -0,0 +1,200 @@
+use crate::TableMap;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::Path;
+
+enum Run {
+    Null(usize),
+    Value(String, usize),
+}
+
+fn encode_runs(column: &[Option<String>]) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for value in column {
+        match (runs.last_mut(), value) {
+            (Some(Run::Null(count)), None) => *count += 1,
+            (Some(Run::Value(v, count)), Some(value)) if v == value => *count += 1,
+            _ => runs.push(match value {
+                None => Run::Null(1),
+                Some(value) => Run::Value(value.clone(), 1),
+            }),
+        }
+    }
+    runs
+}
+
+fn write_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_string(out: &mut Vec<u8>, value: &str) {
+    write_u32(out, value.len() as u32);
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn read_u32(data: &[u8], cursor: &mut usize) -> u32 {
+    let value = u32::from_le_bytes(data[*cursor..*cursor + 4].try_into().unwrap());
+    *cursor += 4;
+    value
+}
+
+fn read_string(data: &[u8], cursor: &mut usize) -> String {
+    let len = read_u32(data, cursor) as usize;
+    let value = String::from_utf8_lossy(&data[*cursor..*cursor + len]).into_owned();
+    *cursor += len;
+    value
+}
+
+fn encode_column(column: &[Option<String>]) -> Vec<u8> {
+    let runs = encode_runs(column);
+    let distinct: std::collections::HashSet<&String> = runs
+        .iter()
+        .filter_map(|run| match run {
+            Run::Value(value, _) => Some(value),
+            Run::Null(_) => None,
+        })
+        .collect();
+    let use_dictionary = distinct.len() < runs.len();
+
+    let mut out = Vec::new();
+    out.push(use_dictionary as u8);
+
+    let mut codes: HashMap<&str, u32> = HashMap::new();
+    if use_dictionary {
+        let mut dict = Vec::new();
+        for run in &runs {
+            if let Run::Value(value, _) = run {
+                if !codes.contains_key(value.as_str()) {
+                    codes.insert(value.as_str(), dict.len() as u32);
+                    dict.push(value.as_str());
+                }
+            }
+        }
+        write_u32(&mut out, dict.len() as u32);
+        for value in &dict {
+            write_string(&mut out, value);
+        }
+    }
+
+    write_u32(&mut out, runs.len() as u32);
+    for run in &runs {
+        match run {
+            Run::Null(count) => {
+                out.push(0);
+                write_u32(&mut out, *count as u32);
+            }
+            Run::Value(value, count) => {
+                out.push(1);
+                write_u32(&mut out, *count as u32);
+                if use_dictionary {
+                    write_u32(&mut out, codes[value.as_str()]);
+                } else {
+                    write_string(&mut out, value);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn decode_column(data: &[u8], cursor: &mut usize) -> Vec<Option<String>> {
+    let use_dictionary = data[*cursor] != 0;
+    *cursor += 1;
+
+    let mut dict = Vec::new();
+    if use_dictionary {
+        let dict_len = read_u32(data, cursor) as usize;
+        for _ in 0..dict_len {
+            dict.push(read_string(data, cursor));
+        }
+    }
+
+    let run_count = read_u32(data, cursor) as usize;
+    let mut column = Vec::new();
+    for _ in 0..run_count {
+        let tag = data[*cursor];
+        *cursor += 1;
+        let count = read_u32(data, cursor) as usize;
+        match tag {
+            0 => column.extend(std::iter::repeat_n(None, count)),
+            1 => {
+                let value = if use_dictionary {
+                    let code = read_u32(data, cursor) as usize;
+                    dict[code].clone()
+                } else {
+                    read_string(data, cursor)
+                };
+                column.extend(std::iter::repeat_n(Some(value), count));
+            }
+            _ => unreachable!("unknown run tag {}", tag),
+        }
+    }
+    column
+}
+
+impl TableMap<String, String> {
+    pub fn save_packed<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let mut out = Vec::new();
+        write_u32(&mut out, self.columns.iter().count() as u32);
+        for (key, column) in self.columns.iter() {
+            write_string(&mut out, key);
+            out.extend(encode_column(column));
+        }
+        write_u32(&mut out, self.len() as u32);
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(path)?;
+        file.write_all(&out)
+    }
+
+    pub fn load_packed<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        File::open(path)?.read_to_end(&mut data)?;
+        let mut cursor = 0usize;
+
+        let column_count = read_u32(&data, &mut cursor) as usize;
+        let mut this = Self::new();
+        for _ in 0..column_count {
+            let key = read_string(&data, &mut cursor);
+            let column = decode_column(&data, &mut cursor);
+            unsafe {
+                this.columns.inner_mut().push((key, column));
+            }
+        }
+        this.len = read_u32(&data, &mut cursor) as usize;
+        Ok(this)
+    }
+}
+
+#[test]
+#[allow(unused_parens)]
+fn round_trips_sparse_low_cardinality_table() {
+    let path = "test_packed.bin";
+    let mut table = TableMap::<String, String>::new();
+    let statuses = ["active", "active", "active", "inactive", "active"];
+    for (i, status) in statuses.iter().enumerate() {
+        let mut entry = crate::add_entry!(table, {("status".to_owned()): status.to_string()});
+        if i == 2 {
+            entry.insert("note".to_owned(), "flagged".to_owned());
+        }
+    }
+    table.save_packed(path).unwrap();
+    let reloaded = TableMap::<String, String>::load_packed(path).unwrap();
+
+    assert_eq!(reloaded.len(), table.len());
+    for i in 0..table.len() {
+        assert_eq!(
+            reloaded.entry(i).get(&"status"),
+            table.entry(i).get(&"status")
+        );
+        assert_eq!(
+            reloaded.entry(i).get(&"note"),
+            table.entry(i).get(&"note")
+        );
+    }
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,121 @@
+use crate::TableMap;
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnStats {
+    pub count: usize,
+    pub null_count: usize,
+    pub distinct_count: usize,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub sum: Option<f64>,
+    pub mean: Option<f64>,
+    pub stddev: Option<f64>,
+}
+
+impl TableMap<String, String> {
+    pub fn stats(&self, key: &str) -> Option<ColumnStats> {
+        let column = self
+            .columns
+            .iter()
+            .find(|(k, _)| k.as_str() == key)
+            .map(|(_, v)| v)?;
+
+        let mut stats = ColumnStats::default();
+        let mut distinct = BTreeSet::new();
+        let mut min: Option<&String> = None;
+        let mut max: Option<&String> = None;
+        let mut numbers = Vec::new();
+
+        for value in column {
+            stats.count += 1;
+            match value {
+                None => stats.null_count += 1,
+                Some(value) => {
+                    distinct.insert(value.as_str());
+                    if min.is_none_or(|m| value < m) {
+                        min = Some(value);
+                    }
+                    if max.is_none_or(|m| value > m) {
+                        max = Some(value);
+                    }
+                    if let Ok(number) = value.parse::<f64>() {
+                        numbers.push(number);
+                    }
+                }
+            }
+        }
+
+        stats.distinct_count = distinct.len();
+        stats.min = min.cloned();
+        stats.max = max.cloned();
+        if !numbers.is_empty() {
+            let sum: f64 = numbers.iter().sum();
+            let mean = sum / numbers.len() as f64;
+            let variance =
+                numbers.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / numbers.len() as f64;
+            stats.sum = Some(sum);
+            stats.mean = Some(mean);
+            stats.stddev = Some(variance.sqrt());
+        }
+        Some(stats)
+    }
+
+    pub fn summary(&self) -> TableMap<String, String> {
+        let mut report = TableMap::new();
+        for key in self.columns.keys() {
+            let stats = match self.stats(key) {
+                Some(stats) => stats,
+                None => continue,
+            };
+            report.new_entry();
+            let row = report.len() - 1;
+            let mut entry = report.entry_mut(row);
+            entry.insert("column".to_owned(), key.clone());
+            entry.insert("count".to_owned(), stats.count.to_string());
+            entry.insert("nulls".to_owned(), stats.null_count.to_string());
+            entry.insert("distinct".to_owned(), stats.distinct_count.to_string());
+            if let Some(min) = stats.min {
+                entry.insert("min".to_owned(), min);
+            }
+            if let Some(max) = stats.max {
+                entry.insert("max".to_owned(), max);
+            }
+            if let Some(sum) = stats.sum {
+                entry.insert("sum".to_owned(), sum.to_string());
+            }
+            if let Some(mean) = stats.mean {
+                entry.insert("mean".to_owned(), mean.to_string());
+            }
+            if let Some(stddev) = stats.stddev {
+                entry.insert("stddev".to_owned(), stddev.to_string());
+            }
+        }
+        report
+    }
+}
+
+#[test]
+#[allow(unused_parens)]
+fn stats_cover_nulls_distinctness_and_numeric_aggregates() {
+    let mut table = TableMap::<String, String>::new();
+    for age in ["30", "18", "18"] {
+        crate::add_entry!(table, {("age".to_owned()): age.to_owned()});
+    }
+    table.new_entry();
+
+    let stats = table.stats("age").unwrap();
+    assert_eq!(stats.count, 4);
+    assert_eq!(stats.null_count, 1);
+    assert_eq!(stats.distinct_count, 2);
+    assert_eq!(stats.min.as_deref(), Some("18"));
+    assert_eq!(stats.max.as_deref(), Some("30"));
+    assert_eq!(stats.sum, Some(66.0));
+
+    let summary = table.summary();
+    assert_eq!(summary.len(), 1);
+    assert_eq!(summary.entry(0).get(&"count").unwrap(), "4");
+}
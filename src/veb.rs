@@ -0,0 +1,164 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+
+pub struct VebTree {
+    bits: u32,
+    min: Option<u32>,
+    max: Option<u32>,
+    summary: Option<Box<VebTree>>,
+    clusters: BTreeMap<usize, VebTree>,
+}
+
+impl VebTree {
+    pub fn new(bits: u32) -> Self {
+        let summary = if bits > 1 {
+            let upper_bits = bits - bits / 2;
+            Some(Box::new(VebTree::new(upper_bits)))
+        } else {
+            None
+        };
+        VebTree {
+            bits,
+            min: None,
+            max: None,
+            summary,
+            clusters: BTreeMap::new(),
+        }
+    }
+
+    fn lower_bits(&self) -> u32 {
+        self.bits / 2
+    }
+
+    fn high(&self, x: u32) -> usize {
+        (x >> self.lower_bits()) as usize
+    }
+
+    fn low(&self, x: u32) -> u32 {
+        x & ((1u32 << self.lower_bits()) - 1)
+    }
+
+    fn index(&self, high: usize, low: u32) -> u32 {
+        ((high as u32) << self.lower_bits()) | low
+    }
+
+    fn cluster_mut(&mut self, high: usize) -> &mut VebTree {
+        let lower_bits = self.lower_bits();
+        self.clusters.entry(high).or_insert_with(|| VebTree::new(lower_bits))
+    }
+
+    pub fn min(&self) -> Option<u32> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<u32> {
+        self.max
+    }
+
+    pub fn insert(&mut self, mut x: u32) {
+        if self.min.is_none() {
+            self.min = Some(x);
+            self.max = Some(x);
+            return;
+        }
+        if x == self.min.unwrap() {
+            return;
+        }
+        if x < self.min.unwrap() {
+            core::mem::swap(&mut x, self.min.as_mut().unwrap());
+        }
+        if self.bits > 1 {
+            let h = self.high(x);
+            let l = self.low(x);
+            let was_empty = self.clusters.get(&h).is_none_or(|cluster| cluster.min.is_none());
+            if was_empty {
+                self.summary.as_mut().unwrap().insert(h as u32);
+            }
+            self.cluster_mut(h).insert(l);
+        }
+        if x > self.max.unwrap() {
+            self.max = Some(x);
+        }
+    }
+
+    pub fn successor(&self, x: u32) -> Option<u32> {
+        if self.bits <= 1 {
+            return if x == 0 && self.max == Some(1) {
+                Some(1)
+            } else {
+                None
+            };
+        }
+        if let Some(min) = self.min {
+            if x < min {
+                return Some(min);
+            }
+        }
+        let h = self.high(x);
+        let l = self.low(x);
+        if let Some(cluster) = self.clusters.get(&h) {
+            if let Some(cluster_max) = cluster.max {
+                if l < cluster_max {
+                    let offset = cluster.successor(l)?;
+                    return Some(self.index(h, offset));
+                }
+            }
+        }
+        let next_cluster = self.summary.as_ref().unwrap().successor(h as u32)?;
+        let offset = self.clusters.get(&(next_cluster as usize))?.min?;
+        Some(self.index(next_cluster as usize, offset))
+    }
+
+    pub fn predecessor(&self, x: u32) -> Option<u32> {
+        if self.bits <= 1 {
+            return if x == 1 && self.min == Some(0) {
+                Some(0)
+            } else {
+                None
+            };
+        }
+        let h = self.high(x);
+        let l = self.low(x);
+        if let Some(cluster) = self.clusters.get(&h) {
+            if let Some(cluster_min) = cluster.min {
+                if l > cluster_min {
+                    let offset = cluster.predecessor(l)?;
+                    return Some(self.index(h, offset));
+                }
+            }
+        }
+        if let Some(prev_cluster) = self.summary.as_ref().unwrap().predecessor(h as u32) {
+            let offset = self.clusters.get(&(prev_cluster as usize))?.max?;
+            return Some(self.index(prev_cluster as usize, offset));
+        }
+        match self.min {
+            Some(min) if x > min => Some(min),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn orders_inserted_keys() {
+    let mut tree = VebTree::new(8);
+    for x in [5u32, 1, 40, 3, 200, 17] {
+        tree.insert(x);
+    }
+    assert_eq!(tree.min(), Some(1));
+    assert_eq!(tree.max(), Some(200));
+    assert_eq!(tree.successor(1), Some(3));
+    assert_eq!(tree.successor(3), Some(5));
+    assert_eq!(tree.successor(17), Some(40));
+    assert_eq!(tree.predecessor(40), Some(17));
+    assert_eq!(tree.predecessor(1), None);
+    assert_eq!(tree.successor(200), None);
+}
+
+#[test]
+fn construction_stays_cheap_for_a_large_universe() {
+    let mut tree = VebTree::new(31);
+    tree.insert(1_753_000_000);
+    tree.insert(1_753_000_500);
+    assert_eq!(tree.min(), Some(1_753_000_000));
+    assert_eq!(tree.successor(1_753_000_000), Some(1_753_000_500));
+}